@@ -43,6 +43,47 @@ pub struct EvolutionSettings {
     // NEW: Optional primary file for dashboard display
     #[serde(default)]
     pub primary_file: Option<String>, // e.g., "src/main.py", "index.js"
+
+    // NEW: How a generation's fitness is judged: "pass_fail" (first green generation wins),
+    // "coverage" (keep evolving, optimizing for line coverage among passing generations), or
+    // "snapshot" (survive only if every `snapshot_cases` entry matches its expected output)
+    #[serde(default = "default_fitness_metric")]
+    pub fitness_metric: String,
+
+    // NEW: Minimum line coverage (0.0-1.0) required for a survivor when fitness_metric =
+    // "coverage" (ignored otherwise); requires a `cargo test`-compatible test_command.
+    #[serde(default)]
+    pub min_coverage: f64,
+
+    // NEW: Number of independent candidate mutations to try per generation. 1 (default)
+    // keeps the original serial hill-climber; >1 switches to population-based evolution
+    // using git worktrees, keeping the fittest candidate as the next generation's parent.
+    #[serde(default = "default_population_size")]
+    pub population_size: u32,
+
+    // NEW: Maximum number of candidates to evaluate concurrently when population_size > 1.
+    #[serde(default = "default_parallelism")]
+    pub parallelism: usize,
+
+    // NEW: Expected-output cases used when fitness_metric = "snapshot". A generation
+    // survives only if every case's (normalized) actual output matches its expected snapshot.
+    #[serde(default)]
+    pub snapshot_cases: Vec<SnapshotCase>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SnapshotCase {
+    pub run_command: String,
+
+    #[serde(default)]
+    pub expected_stdout_file: Option<String>,
+
+    #[serde(default)]
+    pub expected_stderr_file: Option<String>,
+
+    // Whether `run_command` is expected to exit non-zero rather than succeed.
+    #[serde(default)]
+    pub expect_failure: bool,
 }
 
 fn default_test_cmd() -> String {
@@ -53,6 +94,18 @@ fn default_generations() -> u32 {
     5
 }
 
+fn default_fitness_metric() -> String {
+    "pass_fail".to_string()
+}
+
+fn default_population_size() -> u32 {
+    1
+}
+
+fn default_parallelism() -> usize {
+    4
+}
+
 // Default fallback if user doesn't specify files
 fn default_files() -> Vec<String> {
     vec!["src/lib.rs".to_string()]
@@ -1,16 +1,36 @@
 mod config;
 
 use anyhow::{Context, Result};
-use clap::Parser;
-use config::{EvolutionSettings, load_config};
+use clap::{Parser, Subcommand};
+use config::{load_config, EvolutionSettings, SnapshotCase};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::thread;
 use tracing::{error, info, instrument, warn};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {
+struct Cli {
+    #[command(subcommand)]
+    command: EngineCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum EngineCommand {
+    /// Run the mutation/verification evolution loop against a target repository
+    Evolve(EvolveArgs),
+    /// Run only the bootstrap step: scaffold a new project without evolving it
+    Bootstrap(TargetArgs),
+    /// Reset the target repository back to its Genesis snapshot
+    Revert(TargetArgs),
+    /// Show what `evolve` would do for the next generation, without spawning Aider,
+    /// writing scaffold files, or touching git
+    Plan(EvolveArgs),
+}
+
+#[derive(Parser, Debug)]
+struct EvolveArgs {
     /// Path to the target repository (must contain Evolve.toml)
     #[arg(short, long, default_value = ".")]
     target: String,
@@ -22,12 +42,31 @@ struct Args {
     /// The model used for Writing Code (The Editor)
     #[arg(long, default_value = "ollama/qwen3-coder:30b")]
     editor: String,
+
+    /// Print the plan for the next generation instead of running it
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Parser, Debug)]
+struct TargetArgs {
+    /// Path to the target repository (must contain Evolve.toml)
+    #[arg(short, long, default_value = ".")]
+    target: String,
 }
 
 fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
 
-    let args = Args::parse();
+    match Cli::parse().command {
+        EngineCommand::Evolve(args) => run_evolve(&args, args.dry_run),
+        EngineCommand::Plan(args) => run_evolve(&args, true),
+        EngineCommand::Bootstrap(args) => run_bootstrap_command(&args.target),
+        EngineCommand::Revert(args) => run_revert_command(&args.target),
+    }
+}
+
+fn run_evolve(args: &EvolveArgs, dry_run: bool) -> Result<()> {
     let target_dir = &args.target;
 
     info!("🚀 Loading Evolution Engine for: {}", target_dir);
@@ -49,6 +88,10 @@ fn main() -> Result<()> {
         settings.instruction.lines().next().unwrap_or("")
     );
 
+    if dry_run {
+        return print_plan(target_dir, &settings, &args.architect, &args.editor);
+    }
+
     // 3. Bootstrap
     bootstrap_project(target_dir, &settings)?;
 
@@ -57,6 +100,36 @@ fn main() -> Result<()> {
     info!("📌 Baseline Snapshot: {}", &start_commit[0..7]);
 
     // 5. Evolution Loop
+    if settings.population_size > 1 {
+        info!(
+            "🧫 Population mode: {} candidates/generation ({} in parallel).",
+            settings.population_size, settings.parallelism
+        );
+        return run_population_evolution(
+            target_dir,
+            &settings,
+            &start_commit,
+            &args.architect,
+            &args.editor,
+        );
+    }
+    // Tracks the fittest passing generation seen so far (snapshot commit, coverage).
+    let mut best_snapshot: Option<(String, f64)> = None;
+
+    // Normalized diagnostics from the most recent failed generation, fed back into the
+    // next mutation prompt so the agent knows *why* the previous attempt died.
+    let mut previous_failures: Option<String> = None;
+
+    let project_type = settings
+        .project_type
+        .as_deref()
+        .or_else(|| infer_project_type(&settings.files))
+        .unwrap_or("rust");
+
+    // Only "coverage" mode has a graded score worth optimizing across generations;
+    // "pass_fail" and "snapshot" are binary, so the first survivor wins immediately.
+    let keep_evolving = settings.fitness_metric == "coverage";
+
     for generation in 1..=settings.max_generations {
         info!("---------------------------------------------------");
         info!("🧬 Generation {}: Mutation Cycle", generation);
@@ -68,6 +141,7 @@ fn main() -> Result<()> {
             &settings.files,
             &args.architect,
             &args.editor,
+            previous_failures.as_deref(),
         ) {
             Ok(_) => info!("🤖 Agent finished."),
             Err(e) => {
@@ -78,13 +152,48 @@ fn main() -> Result<()> {
         }
 
         // B. Verify (The Judge)
-        match verify_fitness(target_dir, &settings.test_command) {
-            Ok(true) => {
-                info!("✅ SUCCESS: Generation {} survived.", generation);
-                return Ok(());
+        match verify_fitness(
+            target_dir,
+            &settings.test_command,
+            &settings.fitness_metric,
+            project_type,
+            &settings.snapshot_cases,
+        ) {
+            Ok(report) if report.passed && meets_min_coverage(&report, &settings) => {
+                info!(
+                    "✅ SUCCESS: Generation {} survived (coverage: {:.1}%).",
+                    generation,
+                    report.coverage * 100.0
+                );
+                previous_failures = None;
+                let snapshot_sha =
+                    commit_snapshot(target_dir, &format!("Generation {} snapshot", generation))?;
+
+                if !keep_evolving {
+                    info!("🏁 Checking out survivor: {}", &snapshot_sha[0..7]);
+                    return revert_to_snapshot(target_dir, &snapshot_sha);
+                }
+
+                if best_snapshot
+                    .as_ref()
+                    .is_none_or(|(_, best_coverage)| report.coverage > *best_coverage)
+                {
+                    info!(
+                        "🏆 New best snapshot ({:.1}% coverage).",
+                        report.coverage * 100.0
+                    );
+                    best_snapshot = Some((snapshot_sha, report.coverage));
+                }
+                revert_to_snapshot(target_dir, &start_commit)?;
             }
-            Ok(false) => {
-                warn!("❌ FAILURE: Generation {} died. Tests failed.", generation);
+            Ok(report) => {
+                warn!(
+                    "❌ FAILURE: Generation {} died (passed: {}, coverage: {:.1}%).",
+                    generation,
+                    report.passed,
+                    report.coverage * 100.0
+                );
+                previous_failures = report.diagnostics;
                 revert_to_snapshot(target_dir, &start_commit)?;
             }
             Err(e) => {
@@ -94,10 +203,432 @@ fn main() -> Result<()> {
         }
     }
 
-    error!(
-        "💀 Evolution failed after {} generations.",
-        settings.max_generations
+    match best_snapshot {
+        Some((sha, coverage)) => {
+            info!(
+                "🏁 Checking out best snapshot ({:.1}% coverage): {}",
+                coverage * 100.0,
+                &sha[0..7]
+            );
+            revert_to_snapshot(target_dir, &sha)?;
+            Ok(())
+        }
+        None => {
+            error!(
+                "💀 Evolution failed after {} generations.",
+                settings.max_generations
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Run only the bootstrap step against `target_dir`, without entering the evolution loop.
+fn run_bootstrap_command(target_dir: &str) -> Result<()> {
+    if !Path::new(target_dir).join("Evolve.toml").exists() {
+        error!("❌ Missing Evolve.toml in target directory.");
+        anyhow::bail!("Cannot bootstrap a project without instructions.");
+    }
+
+    let config = load_config(target_dir)?;
+    bootstrap_project(target_dir, &config.evolution)?;
+    info!("✅ Bootstrap complete.");
+    Ok(())
+}
+
+/// Reset `target_dir` back to its Genesis snapshot (the most recent baseline commit
+/// that `evolve` created on starting with uncommitted changes).
+fn run_revert_command(target_dir: &str) -> Result<()> {
+    let genesis = find_genesis_commit(target_dir)?;
+    info!("⏪ Reverting to Genesis snapshot: {}", &genesis[0..7]);
+    revert_to_snapshot(target_dir, &genesis)
+}
+
+/// Find the most recent commit titled "Genesis" - the baseline `ensure_git_clean_state`
+/// commits before a run's first generation when the target has uncommitted changes.
+fn find_genesis_commit(target_dir: &str) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(target_dir)
+        .args(["log", "--format=%H", "--grep=^Genesis$"])
+        .output()
+        .context("Failed to look up Genesis snapshot")?;
+
+    String::from_utf8(output.stdout)?
+        .lines()
+        .next()
+        .map(|s| s.to_string())
+        .context("No Genesis snapshot found - has this project been evolved yet?")
+}
+
+/// Dry-run planning mode: show exactly what the next generation would do - the
+/// rendered prompt, the exact Aider invocation, the tracked files, the test command,
+/// and the would-be git commands - without spawning Aider, writing scaffold files, or
+/// touching git.
+fn print_plan(
+    target_dir: &str,
+    settings: &EvolutionSettings,
+    architect_model: &str,
+    editor_model: &str,
+) -> Result<()> {
+    let project_type = settings
+        .project_type
+        .as_deref()
+        .or_else(|| infer_project_type(&settings.files))
+        .unwrap_or("rust");
+
+    let abs_path =
+        fs::canonicalize(target_dir).unwrap_or_else(|_| Path::new(target_dir).to_path_buf());
+
+    println!("PLAN for target: {}", target_dir);
+    println!("Project type:    {}", project_type);
+    println!("Fitness metric:  {}", settings.fitness_metric);
+    println!("Max generations: {}", settings.max_generations);
+    println!();
+
+    println!("Tracked files:");
+    for file in &settings.files {
+        println!("  - {}", file);
+    }
+    println!();
+
+    println!("Test command:    {}", settings.test_command);
+    println!();
+
+    let prompt = build_enhanced_prompt(target_dir, &settings.instruction, &settings.files, None)?;
+
+    print!(
+        "Would run: aider --model {} --editor-model {} --message <prompt below> --yes",
+        architect_model, editor_model
     );
+    for file in &settings.files {
+        print!(" {}", file);
+    }
+    println!();
+    println!("  (cwd: {})", abs_path.display());
+    println!();
+
+    println!("Prompt that would be sent:");
+    println!("---");
+    println!("{}", prompt);
+    println!("---");
+    println!();
+
+    println!("Would-be git commands:");
+    println!("  git init                                    (only if the target has no .git yet)");
+    println!("  git add .");
+    println!(
+        "  git commit -m \"Genesis\"                     (only if there are uncommitted changes)"
+    );
+    println!("  git add -A && git commit -m <snapshot>      (on a surviving generation or candidate)");
+    println!("  git reset --hard <commit> && git clean -fd  (on revert or checkout of a snapshot)");
+    if settings.population_size > 1 {
+        println!(
+            "  git worktree add --detach <candidate-dir> <parent-commit>   (x{} per generation)",
+            settings.population_size
+        );
+        println!("  git worktree remove --force <candidate-dir>                 (cleanup)");
+    }
+
+    Ok(())
+}
+
+/// Whether a passing generation's coverage clears `min_coverage`
+fn meets_min_coverage(report: &FitnessReport, settings: &EvolutionSettings) -> bool {
+    if settings.fitness_metric == "coverage" {
+        report.coverage >= settings.min_coverage
+    } else {
+        true
+    }
+}
+
+/// Commit the current working tree as a candidate snapshot
+fn commit_snapshot(target_dir: &str, message: &str) -> Result<String> {
+    Command::new("git")
+        .current_dir(target_dir)
+        .args(["add", "-A"])
+        .output()?;
+    Command::new("git")
+        .current_dir(target_dir)
+        .args(["commit", "-m", message])
+        .output()?;
+    let output = Command::new("git")
+        .current_dir(target_dir)
+        .args(["rev-parse", "HEAD"])
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+// --- POPULATION ENGINE (PARALLEL CANDIDATES VIA GIT WORKTREES) ---
+
+/// Result of evaluating one population candidate: its reached commit and fitness report.
+struct CandidateResult {
+    sha: String,
+    report: FitnessReport,
+}
+
+/// Per-generation settings shared by every candidate
+#[derive(Clone)]
+struct CandidateContext {
+    architect_model: String,
+    editor_model: String,
+    instruction: String,
+    files: Vec<String>,
+    test_command: String,
+    fitness_metric: String,
+    project_type: String,
+    snapshot_cases: Vec<SnapshotCase>,
+}
+
+impl CandidateContext {
+    fn new(
+        settings: &EvolutionSettings,
+        architect_model: &str,
+        editor_model: &str,
+        project_type: &str,
+    ) -> Self {
+        Self {
+            architect_model: architect_model.to_string(),
+            editor_model: editor_model.to_string(),
+            instruction: settings.instruction.clone(),
+            files: settings.files.clone(),
+            test_command: settings.test_command.clone(),
+            fitness_metric: settings.fitness_metric.clone(),
+            project_type: project_type.to_string(),
+            snapshot_cases: settings.snapshot_cases.clone(),
+        }
+    }
+}
+
+/// Evolve `population_size` candidates per generation in parallel worktrees
+fn run_population_evolution(
+    target_dir: &str,
+    settings: &EvolutionSettings,
+    start_commit: &str,
+    architect_model: &str,
+    editor_model: &str,
+) -> Result<()> {
+    let project_type = settings
+        .project_type
+        .as_deref()
+        .or_else(|| infer_project_type(&settings.files))
+        .unwrap_or("rust");
+
+    let ctx = CandidateContext::new(settings, architect_model, editor_model, project_type);
+    // Only "coverage" mode has a graded score worth optimizing across generations;
+    // "pass_fail" and "snapshot" are binary, so the first winner wins immediately.
+    let keep_evolving = ctx.fitness_metric == "coverage";
+    let mut parent = start_commit.to_string();
+    let mut best_snapshot: Option<(String, f64)> = None;
+    let mut previous_failures: Option<String> = None;
+    let mut worktree_paths: Vec<PathBuf> = Vec::new();
+
+    for generation in 1..=settings.max_generations {
+        info!("---------------------------------------------------");
+        info!(
+            "🧬 Generation {}: Evaluating {} candidates from parent {}",
+            generation,
+            settings.population_size,
+            &parent[0..7]
+        );
+
+        let (candidates, generation_worktrees) = evaluate_population(
+            target_dir,
+            settings,
+            &parent,
+            generation,
+            &ctx,
+            previous_failures.as_deref(),
+        )?;
+        worktree_paths.extend(generation_worktrees);
+
+        let winner = candidates
+            .iter()
+            .filter(|c| c.report.passed && meets_min_coverage(&c.report, settings))
+            .max_by(|a, b| a.report.coverage.partial_cmp(&b.report.coverage).unwrap());
+
+        match winner {
+            Some(w) => {
+                info!(
+                    "✅ SUCCESS: Generation {} winner {} (coverage: {:.1}%).",
+                    generation,
+                    &w.sha[0..7],
+                    w.report.coverage * 100.0
+                );
+                previous_failures = None;
+                if best_snapshot
+                    .as_ref()
+                    .is_none_or(|(_, best_coverage)| w.report.coverage > *best_coverage)
+                {
+                    info!(
+                        "🏆 New best snapshot ({:.1}% coverage).",
+                        w.report.coverage * 100.0
+                    );
+                    best_snapshot = Some((w.sha.clone(), w.report.coverage));
+                }
+                if !keep_evolving {
+                    info!("🏁 Checking out winner: {}", &w.sha[0..7]);
+                    prune_worktrees(target_dir, &worktree_paths)?;
+                    return revert_to_snapshot(target_dir, &w.sha);
+                }
+                // Fast-forward: next generation's candidates mutate from the winner.
+                parent = w.sha.clone();
+            }
+            None => {
+                warn!(
+                    "❌ FAILURE: Generation {} produced no surviving candidates.",
+                    generation
+                );
+                previous_failures = candidates.iter().find_map(|c| c.report.diagnostics.clone());
+            }
+        }
+    }
+
+    prune_worktrees(target_dir, &worktree_paths)?;
+
+    match best_snapshot {
+        Some((sha, coverage)) => {
+            info!(
+                "🏁 Checking out best snapshot ({:.1}% coverage): {}",
+                coverage * 100.0,
+                &sha[0..7]
+            );
+            revert_to_snapshot(target_dir, &sha)
+        }
+        None => {
+            error!(
+                "💀 Evolution failed after {} generations.",
+                settings.max_generations
+            );
+            revert_to_snapshot(target_dir, start_commit)
+        }
+    }
+}
+
+/// Run one generation's candidates, at most `parallelism` at a time
+fn evaluate_population(
+    target_dir: &str,
+    settings: &EvolutionSettings,
+    parent: &str,
+    generation: u32,
+    ctx: &CandidateContext,
+    previous_failures: Option<&str>,
+) -> Result<(Vec<CandidateResult>, Vec<PathBuf>)> {
+    let worktree_root = Path::new(target_dir).join(".evolve-worktrees");
+    fs::create_dir_all(&worktree_root)?;
+
+    let candidate_paths: Vec<PathBuf> = (0..settings.population_size)
+        .map(|i| worktree_root.join(format!("gen{}-candidate{}", generation, i)))
+        .collect();
+
+    let mut results = Vec::new();
+    for chunk in candidate_paths.chunks(settings.parallelism.max(1)) {
+        let handles: Vec<_> = chunk
+            .iter()
+            .cloned()
+            .map(|worktree_path| {
+                let target_dir = target_dir.to_string();
+                let parent = parent.to_string();
+                let ctx = ctx.clone();
+                let previous_failures = previous_failures.map(|s| s.to_string());
+
+                thread::spawn(move || {
+                    evaluate_candidate(
+                        &target_dir,
+                        &worktree_path,
+                        &parent,
+                        &ctx,
+                        previous_failures.as_deref(),
+                    )
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            match handle.join() {
+                Ok(Ok(candidate)) => results.push(candidate),
+                Ok(Err(e)) => warn!("💀 Candidate failed: {}", e),
+                Err(_) => warn!("💀 Candidate thread panicked."),
+            }
+        }
+    }
+
+    Ok((results, candidate_paths))
+}
+
+/// Check out `parent` into its own worktree, mutate it, and verify its fitness.
+fn evaluate_candidate(
+    target_dir: &str,
+    worktree_path: &Path,
+    parent: &str,
+    ctx: &CandidateContext,
+    previous_failures: Option<&str>,
+) -> Result<CandidateResult> {
+    let worktree_dir = worktree_path
+        .to_str()
+        .context("Candidate worktree path is not valid UTF-8")?;
+
+    let status = Command::new("git")
+        .current_dir(target_dir)
+        .args(["worktree", "add", "--detach", worktree_dir, parent])
+        .status()
+        .context("Failed to create candidate worktree")?;
+    if !status.success() {
+        anyhow::bail!("git worktree add failed for {}", worktree_dir);
+    }
+
+    if let Err(e) = run_agent_mutation(
+        worktree_dir,
+        &ctx.instruction,
+        &ctx.files,
+        &ctx.architect_model,
+        &ctx.editor_model,
+        previous_failures,
+    ) {
+        return Ok(CandidateResult {
+            sha: parent.to_string(),
+            report: FitnessReport {
+                passed: false,
+                coverage: 0.0,
+                diagnostics: Some(format!("Agent failed: {}", e)),
+            },
+        });
+    }
+
+    let report = verify_fitness(
+        worktree_dir,
+        &ctx.test_command,
+        &ctx.fitness_metric,
+        &ctx.project_type,
+        &ctx.snapshot_cases,
+    )?;
+
+    let sha = if report.passed {
+        commit_snapshot(worktree_dir, "Candidate snapshot")?
+    } else {
+        parent.to_string()
+    };
+
+    Ok(CandidateResult { sha, report })
+}
+
+/// Remove all candidate worktrees created over the run
+fn prune_worktrees(target_dir: &str, worktree_paths: &[PathBuf]) -> Result<()> {
+    for path in worktree_paths {
+        if let Some(path_str) = path.to_str() {
+            Command::new("git")
+                .current_dir(target_dir)
+                .args(["worktree", "remove", "--force", path_str])
+                .output()?;
+        }
+    }
+    Command::new("git")
+        .current_dir(target_dir)
+        .args(["worktree", "prune"])
+        .output()?;
+    let worktree_root = Path::new(target_dir).join(".evolve-worktrees");
+    if worktree_root.exists() {
+        fs::remove_dir_all(&worktree_root)?;
+    }
     Ok(())
 }
 
@@ -279,6 +810,7 @@ fn run_agent_mutation(
     files: &[String],
     architect_model: &str,
     editor_model: &str,
+    previous_failures: Option<&str>,
 ) -> Result<()> {
     info!(
         "🤖 Spawning Aider (Architect: {} | Editor: {})...",
@@ -286,7 +818,7 @@ fn run_agent_mutation(
     );
 
     // Build enhanced, context-aware prompt
-    let enhanced_prompt = build_enhanced_prompt(target_dir, instruction, files)?;
+    let enhanced_prompt = build_enhanced_prompt(target_dir, instruction, files, previous_failures)?;
 
     let abs_path = std::fs::canonicalize(target_dir)
         .context("Failed to resolve absolute path of target_dir")?;
@@ -313,7 +845,12 @@ fn run_agent_mutation(
 }
 
 /// Build an enhanced, context-aware prompt for the AI agent
-fn build_enhanced_prompt(target_dir: &str, instruction: &str, files: &[String]) -> Result<String> {
+fn build_enhanced_prompt(
+    target_dir: &str,
+    instruction: &str,
+    files: &[String],
+    previous_failures: Option<&str>,
+) -> Result<String> {
     // Detect project type from files
     let project_type = infer_project_type(files).unwrap_or("rust");
 
@@ -363,6 +900,13 @@ fn build_enhanced_prompt(target_dir: &str, instruction: &str, files: &[String])
 
     prompt.push_str(&format!("Test command: {}\n\n", test_command));
 
+    // PREVIOUS ATTEMPT FAILED WITH (feedback from the last failed generation, if any)
+    if let Some(failures) = previous_failures {
+        prompt.push_str("PREVIOUS ATTEMPT FAILED WITH:\n");
+        prompt.push_str(failures);
+        prompt.push_str("\n\n");
+    }
+
     // CODE QUALITY REQUIREMENTS
     prompt.push_str("CODE QUALITY REQUIREMENTS:\n");
     prompt.push_str(&get_code_quality_requirements(project_type));
@@ -476,16 +1020,490 @@ fn get_test_quality_requirements(project_type: &str) -> String {
     }
 }
 
+/// Outcome of a single generation's verification
+#[derive(Debug, Clone)]
+struct FitnessReport {
+    passed: bool,
+    coverage: f64,
+    diagnostics: Option<String>,
+}
+
 #[instrument]
-fn verify_fitness(target_dir: &str, test_cmd: &str) -> Result<bool> {
+fn verify_fitness(
+    target_dir: &str,
+    test_cmd: &str,
+    fitness_metric: &str,
+    project_type: &str,
+    snapshot_cases: &[SnapshotCase],
+) -> Result<FitnessReport> {
+    if fitness_metric == "snapshot" {
+        return verify_snapshot_fitness(target_dir, snapshot_cases);
+    }
+
     info!("🧪 Verifying: '{}'", test_cmd);
-    let parts: Vec<&str> = test_cmd.split_whitespace().collect();
+    let mut parts: Vec<&str> = test_cmd.split_whitespace().collect();
     if parts.is_empty() {
-        return Ok(false);
+        return Ok(FitnessReport {
+            passed: false,
+            coverage: 0.0,
+            diagnostics: None,
+        });
+    }
+    // Ask cargo for structured compiler diagnostics instead of relying on its
+    // human-readable text, so extract_failure_diagnostics can take each message's
+    // `rendered` field rather than sniffing substrings (test-harness pass/fail lines are still
+    // plain text - cargo doesn't cover those - so that part keeps its text heuristic).
+    if project_type == "rust"
+        && parts.first() == Some(&"cargo")
+        && parts.get(1) == Some(&"test")
+        && !parts.contains(&"--message-format=json")
+    {
+        parts.insert(2, "--message-format=json");
     }
     let output = Command::new(parts[0])
         .current_dir(target_dir)
         .args(&parts[1..])
         .output()?;
-    Ok(output.status.success())
+    let passed = output.status.success();
+
+    let diagnostics = if passed {
+        None
+    } else {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Some(extract_failure_diagnostics(
+            target_dir,
+            project_type,
+            &stdout,
+            &stderr,
+        ))
+    };
+
+    let coverage = if fitness_metric == "coverage" {
+        measure_coverage(target_dir, test_cmd).unwrap_or_else(|e| {
+            warn!("⚠️  Coverage measurement failed: {}", e);
+            0.0
+        })
+    } else {
+        0.0
+    };
+
+    Ok(FitnessReport {
+        passed,
+        coverage,
+        diagnostics,
+    })
+}
+
+/// A `cargo ... --message-format=json` line describing one compiler diagnostic.
+#[derive(serde::Deserialize)]
+struct CargoCompilerMessage {
+    reason: String,
+    message: Option<CompilerMessageBody>,
+}
+
+#[derive(serde::Deserialize)]
+struct CompilerMessageBody {
+    rendered: Option<String>,
+}
+
+/// Pull the failing test names, assertion messages, and compiler errors out of a test
+/// run's combined stdout/stderr into a stable, minimal report for the next mutation prompt.
+fn extract_failure_diagnostics(
+    target_dir: &str,
+    project_type: &str,
+    stdout: &str,
+    stderr: &str,
+) -> String {
+    let combined = format!("{}\n{}", stdout, stderr);
+    let relevant: Vec<String> = combined
+        .lines()
+        .filter_map(
+            |line| match serde_json::from_str::<CargoCompilerMessage>(line) {
+                Ok(msg) if msg.reason == "compiler-message" => msg.message.and_then(|m| m.rendered),
+                Ok(_) => None,
+                Err(_) if is_relevant_diagnostic_line(project_type, line) => Some(line.to_string()),
+                Err(_) => None,
+            },
+        )
+        .collect();
+
+    let report = if relevant.is_empty() {
+        // No recognizable failure markers - fall back to the tail of the raw output.
+        let mut tail: Vec<&str> = combined.lines().rev().take(40).collect();
+        tail.reverse();
+        tail.join("\n")
+    } else {
+        relevant.join("\n")
+    };
+
+    normalize_diagnostic_text(target_dir, &report)
+}
+
+/// Whether a line looks like a failing test name, assertion, or compiler error
+fn is_relevant_diagnostic_line(project_type: &str, line: &str) -> bool {
+    let trimmed = line.trim();
+    match project_type {
+        "python" => {
+            trimmed.starts_with("FAILED")
+                || trimmed.starts_with("E ")
+                || trimmed.contains("AssertionError")
+                || trimmed.contains("Error:")
+        }
+        _ => {
+            trimmed.starts_with("FAILED")
+                || (trimmed.starts_with("test ") && trimmed.contains("FAILED"))
+                || trimmed.contains("panicked at")
+                || trimmed.contains("assertion")
+                || trimmed.starts_with("error[")
+                || trimmed.starts_with("error:")
+                || trimmed.starts_with("-->")
+        }
+    }
+}
+
+/// Strip the target dir's absolute path, build-timing lines, and temp-dir paths
+fn normalize_diagnostic_text(target_dir: &str, text: &str) -> String {
+    let abs_prefix = fs::canonicalize(target_dir)
+        .ok()
+        .and_then(|p| p.to_str().map(|s| s.to_string()));
+
+    text.lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            !(trimmed.starts_with("Compiling ")
+                || trimmed.starts_with("Finished ")
+                || trimmed.starts_with("Running ")
+                || trimmed.starts_with("Downloaded ")
+                || trimmed.starts_with("Downloading "))
+        })
+        .map(|line| {
+            let line = match &abs_prefix {
+                Some(prefix) => line.replace(prefix.as_str(), "."),
+                None => line.to_string(),
+            };
+            replace_temp_dir_paths(&line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Replace volatile `/tmp/<hash>` style path segments with a stable `$TMPDIR` token.
+fn replace_temp_dir_paths(line: &str) -> String {
+    let mut result = String::new();
+    let mut rest = line;
+    while let Some(idx) = rest.find("/tmp/") {
+        result.push_str(&rest[..idx]);
+        result.push_str("$TMPDIR");
+        rest = &rest[idx + "/tmp/".len()..];
+        let end = rest
+            .find(|c: char| c == '/' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Run cargo-tarpaulin against `test_cmd` and return covered-line ratio in `[0.0, 1.0]`
+fn measure_coverage(target_dir: &str, test_cmd: &str) -> Result<f64> {
+    let extra_args = test_cmd
+        .trim()
+        .strip_prefix("cargo test")
+        .map(str::trim)
+        .context(
+            "fitness_metric = \"coverage\" requires a `cargo test`-compatible test_command \
+         (cargo-tarpaulin drives its own `cargo test` run, so it can't honor other runners)",
+        )?;
+
+    let mut tarpaulin_args = vec![
+        "tarpaulin",
+        "--out",
+        "Json",
+        "--output-dir",
+        ".evolve-coverage",
+    ];
+    let extra: Vec<&str> = extra_args.split_whitespace().collect();
+    if !extra.is_empty() {
+        tarpaulin_args.push("--");
+        tarpaulin_args.extend(extra);
+    }
+
+    let output = Command::new("cargo")
+        .current_dir(target_dir)
+        .args(&tarpaulin_args)
+        .output()
+        .context("Failed to run cargo-tarpaulin")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "cargo-tarpaulin exited with failure: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let report_path = Path::new(target_dir).join(".evolve-coverage/tarpaulin-report.json");
+    let content = fs::read_to_string(&report_path).context("Failed to read tarpaulin report")?;
+    let report: serde_json::Value =
+        serde_json::from_str(&content).context("Failed to parse tarpaulin report as JSON")?;
+
+    // tarpaulin reports an aggregate percentage directly when available.
+    if let Some(pct) = report.get("coverage").and_then(|v| v.as_f64()) {
+        return Ok(pct / 100.0);
+    }
+
+    // Fall back to summing covered/coverable lines across each file entry.
+    let files = report
+        .get("files")
+        .and_then(|v| v.as_array())
+        .context("Malformed tarpaulin report: missing 'files'")?;
+
+    let mut covered_lines = 0u64;
+    let mut total_lines = 0u64;
+    for file in files {
+        covered_lines += file
+            .get("covered")
+            .and_then(|v| v.as_array())
+            .map_or(0, |lines| lines.len() as u64);
+        total_lines += file
+            .get("coverable")
+            .and_then(|v| v.as_array())
+            .map_or(0, |lines| lines.len() as u64);
+    }
+
+    if total_lines == 0 {
+        return Ok(0.0);
+    }
+    Ok(covered_lines as f64 / total_lines as f64)
+}
+
+// --- SNAPSHOT VERIFICATION (EXPECTED-OUTPUT MODE) ---
+
+/// Run every configured `SnapshotCase`; a generation survives only if all of them match
+fn verify_snapshot_fitness(target_dir: &str, cases: &[SnapshotCase]) -> Result<FitnessReport> {
+    if cases.is_empty() {
+        return Ok(FitnessReport {
+            passed: false,
+            coverage: 0.0,
+            diagnostics: Some(
+                "fitness_metric = \"snapshot\" but no snapshot_cases are configured.".to_string(),
+            ),
+        });
+    }
+
+    let mut mismatches = Vec::new();
+
+    for case in cases {
+        info!("🧪 Verifying snapshot case: '{}'", case.run_command);
+        let parts: Vec<&str> = case.run_command.split_whitespace().collect();
+        if parts.is_empty() {
+            mismatches.push(format!("Case '{}': run_command is empty", case.run_command));
+            continue;
+        }
+
+        let output = Command::new(parts[0])
+            .current_dir(target_dir)
+            .args(&parts[1..])
+            .output()
+            .with_context(|| format!("Failed to run snapshot case '{}'", case.run_command))?;
+
+        if case.expect_failure && output.status.success() {
+            mismatches.push(format!(
+                "Case '{}': expected a non-zero exit status, but it succeeded",
+                case.run_command
+            ));
+            continue;
+        }
+        if !case.expect_failure && !output.status.success() {
+            mismatches.push(format!(
+                "Case '{}': expected success, but it exited with {:?}",
+                case.run_command,
+                output.status.code()
+            ));
+            continue;
+        }
+
+        let actual_stdout =
+            normalize_snapshot_text(target_dir, &String::from_utf8_lossy(&output.stdout));
+        let actual_stderr =
+            normalize_snapshot_text(target_dir, &String::from_utf8_lossy(&output.stderr));
+
+        if let Some(expected_file) = &case.expected_stdout_file {
+            check_snapshot_stream(
+                target_dir,
+                &case.run_command,
+                "stdout",
+                expected_file,
+                &actual_stdout,
+                &mut mismatches,
+            )?;
+        }
+        if let Some(expected_file) = &case.expected_stderr_file {
+            check_snapshot_stream(
+                target_dir,
+                &case.run_command,
+                "stderr",
+                expected_file,
+                &actual_stderr,
+                &mut mismatches,
+            )?;
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(FitnessReport {
+            passed: true,
+            coverage: 0.0,
+            diagnostics: None,
+        })
+    } else {
+        let diagnostics = mismatches.join("\n\n");
+        warn!("📝 Snapshot mismatch(es) detected:\n{}", diagnostics);
+        Ok(FitnessReport {
+            passed: false,
+            coverage: 0.0,
+            diagnostics: Some(diagnostics),
+        })
+    }
+}
+
+/// Compare one output stream against its expected snapshot file, appending a
+/// unified diff to `mismatches` on a mismatch
+fn check_snapshot_stream(
+    target_dir: &str,
+    run_command: &str,
+    stream_name: &str,
+    expected_file: &str,
+    actual_normalized: &str,
+    mismatches: &mut Vec<String>,
+) -> Result<()> {
+    let expected_raw = fs::read_to_string(Path::new(target_dir).join(expected_file))
+        .with_context(|| format!("Failed to read expected snapshot file '{}'", expected_file))?;
+    let expected_normalized = normalize_snapshot_text(target_dir, &expected_raw);
+
+    if expected_normalized != actual_normalized {
+        mismatches.push(format!(
+            "Case '{}' {} mismatch ({}):\n{}",
+            run_command,
+            stream_name,
+            expected_file,
+            unified_diff(&expected_normalized, actual_normalized)
+        ));
+    }
+    Ok(())
+}
+
+/// Mask `$DIR`, temp-dir paths, `line:col`, and trailing whitespace so a snapshot
+/// compares stably across runs
+fn normalize_snapshot_text(target_dir: &str, text: &str) -> String {
+    let abs_prefix = fs::canonicalize(target_dir)
+        .ok()
+        .and_then(|p| p.to_str().map(|s| s.to_string()));
+
+    text.lines()
+        .map(|line| {
+            let mut line = line.trim_end().to_string();
+            if let Some(prefix) = &abs_prefix {
+                line = line.replace(prefix.as_str(), "$DIR");
+            }
+            let line = replace_temp_dir_paths(&line);
+            mask_line_col_numbers(&line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Mask `:<line>:<col>` locations (e.g. `src/main.rs:12:5`) down to `:$LINE:$COL`
+fn mask_line_col_numbers(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut result = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == ':' && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+            let mut j = i + 1;
+            while j < chars.len() && chars[j].is_ascii_digit() {
+                j += 1;
+            }
+            if chars.get(j) == Some(&':') && chars.get(j + 1).is_some_and(|c| c.is_ascii_digit()) {
+                let mut k = j + 1;
+                while k < chars.len() && chars[k].is_ascii_digit() {
+                    k += 1;
+                }
+                result.push_str(":$LINE:$COL");
+                i = k;
+                continue;
+            }
+        }
+        result.push(chars[i]);
+        i += 1;
+    }
+    result
+}
+
+/// Render a minimal unified-style diff between two already-normalized texts
+fn unified_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let line_count = expected_lines.len().max(actual_lines.len());
+
+    let mut diff = String::from("--- expected\n+++ actual\n");
+    for i in 0..line_count {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!(" {}\n", e)),
+            (Some(e), Some(a)) => {
+                diff.push_str(&format!("-{}\n", e));
+                diff.push_str(&format!("+{}\n", a));
+            }
+            (Some(e), None) => diff.push_str(&format!("-{}\n", e)),
+            (None, Some(a)) => diff.push_str(&format!("+{}\n", a)),
+            (None, None) => {}
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_line_col_numbers_masks_locations() {
+        assert_eq!(
+            mask_line_col_numbers("thread panicked at src/main.rs:12:5"),
+            "thread panicked at src/main.rs:$LINE:$COL"
+        );
+    }
+
+    #[test]
+    fn mask_line_col_numbers_ignores_single_colon_number() {
+        assert_eq!(mask_line_col_numbers("port:8080"), "port:8080");
+    }
+
+    #[test]
+    fn normalize_snapshot_text_masks_line_col_and_trims_whitespace() {
+        let normalized = normalize_snapshot_text(".", "error at foo.rs:12:5   \nok");
+        assert_eq!(normalized, "error at foo.rs:$LINE:$COL\nok");
+    }
+
+    #[test]
+    fn normalize_snapshot_text_replaces_temp_dir_paths() {
+        let normalized = normalize_snapshot_text(".", "reading /tmp/abc123/scratch.txt");
+        assert_eq!(normalized, "reading $TMPDIR/scratch.txt");
+    }
+
+    #[test]
+    fn unified_diff_marks_matching_lines_unchanged() {
+        assert_eq!(
+            unified_diff("same\n", "same\n"),
+            "--- expected\n+++ actual\n same\n"
+        );
+    }
+
+    #[test]
+    fn unified_diff_marks_mismatched_and_unaligned_lines() {
+        let diff = unified_diff("a\nb\n", "a\nc\nd\n");
+        assert_eq!(diff, "--- expected\n+++ actual\n a\n-b\n+c\n+d\n");
+    }
 }